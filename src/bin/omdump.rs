@@ -1,7 +1,12 @@
 use omfiles_rs::backend::mmapfile::MmapFile;
+use omfiles_rs::core::compression::CompressionType;
+use omfiles_rs::core::data_types::DataType;
 use omfiles_rs::errors::OmFilesRsError;
 use omfiles_rs::io::reader::OmFileReader;
+use omfiles_rs::io::writer::OmFileWriter;
+use serde::{Deserialize, Serialize};
 use std::env;
+use std::fs::File;
 use std::ops::Range;
 
 /// Display information about a variable and its children recursively
@@ -55,6 +60,271 @@ fn print_variable_info(reader: &OmFileReader<MmapFile>, indent: usize, path: &st
     }
 }
 
+/// A JSON-serializable snapshot of a variable's metadata and its child structure, without
+/// any of the actual data payload. Used by `dump`/`restore` to archive and reconstruct the
+/// shape of an OM file independently of its contents.
+#[derive(Debug, Serialize, Deserialize)]
+struct VariableDump {
+    name: Option<String>,
+    data_type: String,
+    compression: String,
+    dimensions: Vec<u64>,
+    chunk_dimensions: Vec<u64>,
+    scale_factor: f64,
+    add_offset: f64,
+    children: Vec<VariableDump>,
+}
+
+fn dump_variable(reader: &OmFileReader<MmapFile>) -> VariableDump {
+    let mut children = Vec::new();
+    for i in 0..reader.number_of_children() {
+        if let Some(child) = reader.get_child(i) {
+            children.push(dump_variable(&child));
+        }
+    }
+
+    VariableDump {
+        name: reader.get_name(),
+        data_type: format!("{:?}", reader.data_type()),
+        compression: format!("{:?}", reader.compression()),
+        dimensions: reader.get_dimensions().to_vec(),
+        chunk_dimensions: reader.get_chunk_dimensions().to_vec(),
+        scale_factor: reader.scale_factor(),
+        add_offset: reader.add_offset(),
+        children,
+    }
+}
+
+fn dump(om_path: &str, json_path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let reader = OmFileReader::from_file(om_path)?;
+    let root = dump_variable(&reader);
+    let json = serde_json::to_string_pretty(&root)?;
+    std::fs::write(json_path, json)?;
+    println!("Dumped metadata tree of {} to {}", om_path, json_path);
+    Ok(())
+}
+
+fn parse_compression(s: &str) -> CompressionType {
+    match s {
+        "P4nzdec256" => CompressionType::P4nzdec256,
+        "Fpxdec32" => CompressionType::Fpxdec32,
+        "P4nzdec256Logarithmic" => CompressionType::P4nzdec256Logarithmic,
+        "P4nzdec256Int16" => CompressionType::P4nzdec256Int16,
+        "P4nzdec256Int16Logarithmic" => CompressionType::P4nzdec256Int16Logarithmic,
+        other => panic!("Unknown compression type in dump: {}", other),
+    }
+}
+
+/// Write a skeleton for one dumped variable (and its children, depth-first) into
+/// `file_writer`, filling its array with zeroed placeholder data since the OM format
+/// requires every declared chunk to actually be written. Placeholder data is written one
+/// chunk-aligned range at a time so memory stays bounded regardless of the variable's size.
+fn restore_variable(
+    file_writer: &mut OmFileWriter<&File>,
+    meta: &VariableDump,
+) -> Result<omfiles_rs::io::writer::OmOffsetSize, Box<dyn std::error::Error>> {
+    let mut child_refs = Vec::with_capacity(meta.children.len());
+    for child in &meta.children {
+        child_refs.push(restore_variable(file_writer, child)?);
+    }
+
+    let dims = meta.dimensions.clone();
+    let chunks = meta.chunk_dimensions.clone();
+    let compression = parse_compression(&meta.compression);
+
+    let chunk_ranges = if dims.is_empty() || chunks.is_empty() {
+        vec![vec![]]
+    } else {
+        all_chunk_ranges(&dims, &chunks)
+    };
+
+    macro_rules! write_placeholder {
+        ($t:ty, $default:expr) => {{
+            let mut array_writer = file_writer
+                .prepare_array::<$t>(
+                    dims.clone(),
+                    chunks.clone(),
+                    compression,
+                    meta.scale_factor,
+                    meta.add_offset,
+                )
+                .expect("Failed to prepare array");
+            for ranges in &chunk_ranges {
+                let shape: Vec<usize> = ranges.iter().map(|r| (r.end - r.start) as usize).collect();
+                let placeholder =
+                    ndarray::ArrayD::<$t>::from_elem(ndarray::IxDyn(&shape), $default);
+                array_writer
+                    .write_data(placeholder.view(), None, None)
+                    .expect("Failed to write placeholder data");
+            }
+            array_writer.finalize()
+        }};
+    }
+
+    let variable_meta = match meta.data_type.as_str() {
+        "Int8" => write_placeholder!(i8, 0i8),
+        "Uint8" => write_placeholder!(u8, 0u8),
+        "Int16" => write_placeholder!(i16, 0i16),
+        "Uint16" => write_placeholder!(u16, 0u16),
+        "Int32" => write_placeholder!(i32, 0i32),
+        "Uint32" => write_placeholder!(u32, 0u32),
+        "Int64" => write_placeholder!(i64, 0i64),
+        "Uint64" => write_placeholder!(u64, 0u64),
+        "Float" => write_placeholder!(f32, 0f32),
+        "Double" => write_placeholder!(f64, 0f64),
+        other => panic!("Unknown data type in dump: {}", other),
+    };
+
+    let name = meta.name.as_deref().unwrap_or("");
+    file_writer
+        .write_array(variable_meta, name, &child_refs)
+        .map_err(|e| format!("Failed to write variable metadata: {:?}", e).into())
+}
+
+fn restore(json_path: &str, om_path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let json_data = std::fs::read_to_string(json_path)?;
+    let root: VariableDump = serde_json::from_str(&json_data)?;
+
+    let file_handle = File::create(om_path).expect("Failed to create output file");
+    let mut file_writer = OmFileWriter::new(&file_handle, 1024 * 1024);
+
+    let root_variable = restore_variable(&mut file_writer, &root)?;
+    file_writer.write_trailer(root_variable)?;
+
+    println!("Restored skeleton from {} to {}", json_path, om_path);
+    Ok(())
+}
+
+/// Split `dimension` into chunk-aligned `[start, end)` ranges of size `chunk_dimension`,
+/// with the last range truncated to fit.
+fn chunk_aligned_ranges(dimension: u64, chunk_dimension: u64) -> Vec<Range<u64>> {
+    assert!(
+        chunk_dimension > 0,
+        "chunk dimension must be non-zero (dimension {})",
+        dimension
+    );
+    let mut ranges = Vec::new();
+    let mut start = 0u64;
+    while start < dimension {
+        let end = (start + chunk_dimension).min(dimension);
+        ranges.push(start..end);
+        start = end;
+    }
+    ranges
+}
+
+/// Every chunk-aligned range tiling the full `dimensions`, i.e. the cartesian product of
+/// `chunk_aligned_ranges` across all axes.
+fn all_chunk_ranges(dimensions: &[u64], chunk_dimensions: &[u64]) -> Vec<Vec<Range<u64>>> {
+    let mut combinations: Vec<Vec<Range<u64>>> = vec![vec![]];
+    for (&dimension, &chunk_dimension) in dimensions.iter().zip(chunk_dimensions.iter()) {
+        let axis_ranges = chunk_aligned_ranges(dimension, chunk_dimension);
+        combinations = combinations
+            .into_iter()
+            .flat_map(|prefix| {
+                axis_ranges.iter().map(move |range| {
+                    let mut next = prefix.clone();
+                    next.push(range.clone());
+                    next
+                })
+            })
+            .collect();
+    }
+    combinations
+}
+
+/// Read one chunk and report whether it decompressed cleanly and (for float/double variables)
+/// holds only finite values. Integer variables are only checked for successful decompression —
+/// the raw stored values carry no declared valid range to check against.
+fn chunk_is_healthy(reader: &OmFileReader<MmapFile>, ranges: &[Range<u64>]) -> bool {
+    match reader.data_type() {
+        DataType::Int8 => reader.read::<i8>(ranges, None, None).is_ok(),
+        DataType::Uint8 => reader.read::<u8>(ranges, None, None).is_ok(),
+        DataType::Int16 => reader.read::<i16>(ranges, None, None).is_ok(),
+        DataType::Uint16 => reader.read::<u16>(ranges, None, None).is_ok(),
+        DataType::Int32 => reader.read::<i32>(ranges, None, None).is_ok(),
+        DataType::Uint32 => reader.read::<u32>(ranges, None, None).is_ok(),
+        DataType::Int64 => reader.read::<i64>(ranges, None, None).is_ok(),
+        DataType::Uint64 => reader.read::<u64>(ranges, None, None).is_ok(),
+        DataType::Float => reader
+            .read::<f32>(ranges, None, None)
+            .map(|data| data.iter().all(|v| v.is_finite()))
+            .unwrap_or(false),
+        DataType::Double => reader
+            .read::<f64>(ranges, None, None)
+            .map(|data| data.iter().all(|v| v.is_finite()))
+            .unwrap_or(false),
+        other => {
+            eprintln!("  skipping chunk of unsupported data type {:?}", other);
+            true
+        }
+    }
+}
+
+struct CheckSummary {
+    total_chunks: usize,
+    readable_chunks: usize,
+    corrupt_ranges: Vec<(String, Vec<Range<u64>>)>,
+}
+
+/// Walk `reader` and all of its children, attempting to read every chunk-aligned range
+/// that tiles each variable's dimensions, and accumulate the results into `summary`.
+fn check_variable(reader: &OmFileReader<MmapFile>, path: &str, summary: &mut CheckSummary) {
+    let dimensions = reader.get_dimensions();
+    let chunk_dimensions = reader.get_chunk_dimensions();
+
+    if !dimensions.is_empty() && !chunk_dimensions.is_empty() {
+        for ranges in all_chunk_ranges(&dimensions, &chunk_dimensions) {
+            summary.total_chunks += 1;
+            if chunk_is_healthy(reader, &ranges) {
+                summary.readable_chunks += 1;
+            } else {
+                summary.corrupt_ranges.push((path.to_string(), ranges));
+            }
+        }
+    }
+
+    for i in 0..reader.number_of_children() {
+        if let Some(child) = reader.get_child(i) {
+            let child_name = child.get_name().unwrap_or_else(|| format!("child_{}", i));
+            let child_path = if path.is_empty() {
+                child_name
+            } else {
+                format!("{}/{}", path, child_name)
+            };
+            check_variable(&child, &child_path, summary);
+        }
+    }
+}
+
+/// `fsck`-style integrity check: decompress every chunk of every variable and report a
+/// summary, exiting non-zero if any chunk failed to read or (for float/double variables)
+/// held non-finite values. Integer variables have no declared valid range, so corruption
+/// there is only caught when it breaks decompression outright.
+fn check(om_path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let reader = OmFileReader::from_file(om_path)?;
+    let mut summary = CheckSummary {
+        total_chunks: 0,
+        readable_chunks: 0,
+        corrupt_ranges: Vec::new(),
+    };
+    check_variable(&reader, "", &mut summary);
+
+    println!("Checked {}", om_path);
+    println!("  total chunks:    {}", summary.total_chunks);
+    println!("  readable chunks: {}", summary.readable_chunks);
+    if summary.corrupt_ranges.is_empty() {
+        println!("  all chunks OK");
+        Ok(())
+    } else {
+        println!("  corrupt chunks:  {}", summary.corrupt_ranges.len());
+        for (path, ranges) in &summary.corrupt_ranges {
+            println!("    {}: {:?}", path, ranges);
+        }
+        std::process::exit(1);
+    }
+}
+
 fn parse_range(range_str: &str) -> Option<Range<u64>> {
     let parts: Vec<&str> = range_str.split("..").collect();
     if parts.len() != 2 {
@@ -70,8 +340,15 @@ fn print_usage(program: &str) {
         "Usage:
   {0} <om-file>
       # Info dump (recursive)
-  {0} <om-file> <var-path> <dim0_range> [<dim1_range> ...]
+  {0} <om-file> <var-path> <dim0_range> [<dim1_range> ...] [--format debug|csv|ndjson]
       # Read values from a variable (by path) and ranges
+  {0} dump <om-file> <out.json>
+      # Serialize the full metadata tree (names, types, dimensions, chunks, ...) to JSON
+  {0} restore <in.json> <out-om-file>
+      # Rebuild a zero-filled OM skeleton from a JSON metadata tree produced by `dump`
+  {0} check <om-file>
+      # Decompress every chunk of every variable and report any that fail to read or
+      # (float/double only) contain NaN/Inf; integer chunks are not range-checked
 
   <var-path> can be:
     - the variable name (e.g. 'data')
@@ -86,22 +363,118 @@ fn print_usage(program: &str) {
     );
 }
 
+#[derive(Clone, Copy, Debug)]
+enum OutputFormat {
+    Debug,
+    Csv,
+    Ndjson,
+}
+
+impl OutputFormat {
+    fn from_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "debug" => Some(OutputFormat::Debug),
+            "csv" => Some(OutputFormat::Csv),
+            "ndjson" => Some(OutputFormat::Ndjson),
+            _ => None,
+        }
+    }
+}
+
+fn print_values<T: std::fmt::Debug + std::fmt::Display>(
+    data: ndarray::ArrayD<T>,
+    format: OutputFormat,
+) {
+    match format {
+        OutputFormat::Debug => println!("{:?}", data),
+        OutputFormat::Csv => {
+            let values: Vec<String> = data.iter().map(|v| v.to_string()).collect();
+            println!("{}", values.join(","));
+        }
+        OutputFormat::Ndjson => {
+            for value in data.iter() {
+                println!("{{\"value\":{}}}", value);
+            }
+        }
+    }
+}
+
 fn print_variable_data(
     variable: &OmFileReader<MmapFile>,
     ranges: &Vec<Range<u64>>,
+    format: OutputFormat,
 ) -> Result<(), OmFilesRsError> {
-    // Only f32 is supported here, but we could extend this with a match on variable.data_type()
-    let data = variable
-        .read::<f32>(&ranges, None, None)
-        .expect("Failed to read data");
-
-    println!("{:?}", data);
+    match variable.data_type() {
+        DataType::Int8 => print_values(
+            variable.read::<i8>(ranges, None, None).expect("Failed to read data"),
+            format,
+        ),
+        DataType::Uint8 => print_values(
+            variable.read::<u8>(ranges, None, None).expect("Failed to read data"),
+            format,
+        ),
+        DataType::Int16 => print_values(
+            variable.read::<i16>(ranges, None, None).expect("Failed to read data"),
+            format,
+        ),
+        DataType::Uint16 => print_values(
+            variable.read::<u16>(ranges, None, None).expect("Failed to read data"),
+            format,
+        ),
+        DataType::Int32 => print_values(
+            variable.read::<i32>(ranges, None, None).expect("Failed to read data"),
+            format,
+        ),
+        DataType::Uint32 => print_values(
+            variable.read::<u32>(ranges, None, None).expect("Failed to read data"),
+            format,
+        ),
+        DataType::Int64 => print_values(
+            variable.read::<i64>(ranges, None, None).expect("Failed to read data"),
+            format,
+        ),
+        DataType::Uint64 => print_values(
+            variable.read::<u64>(ranges, None, None).expect("Failed to read data"),
+            format,
+        ),
+        DataType::Float => print_values(
+            variable.read::<f32>(ranges, None, None).expect("Failed to read data"),
+            format,
+        ),
+        DataType::Double => print_values(
+            variable.read::<f64>(ranges, None, None).expect("Failed to read data"),
+            format,
+        ),
+        other => {
+            eprintln!("Unsupported data type: {:?}", other);
+        }
+    }
     Ok(())
 }
 
 fn main() -> Result<(), OmFilesRsError> {
     let args: Vec<String> = env::args().collect();
 
+    if args.len() == 4 && args[1] == "dump" {
+        dump(&args[2], &args[3]).unwrap_or_else(|e| {
+            eprintln!("Failed to dump metadata: {}", e);
+            std::process::exit(1);
+        });
+        return Ok(());
+    } else if args.len() == 4 && args[1] == "restore" {
+        restore(&args[2], &args[3]).unwrap_or_else(|e| {
+            eprintln!("Failed to restore skeleton: {}", e);
+            std::process::exit(1);
+        });
+        return Ok(());
+    } else if args.len() == 3 && args[1] == "check" {
+        check(&args[2]).unwrap_or_else(|e| {
+            eprintln!("Failed to check file: {}", e);
+            std::process::exit(1);
+        });
+        return Ok(());
+    }
+
     if args.len() == 2 {
         // Info dump mode
         let filename = &args[1];
@@ -114,7 +487,24 @@ fn main() -> Result<(), OmFilesRsError> {
         // Value read mode
         let filename = &args[1];
         let var_path = &args[2];
-        let ranges: Vec<Option<Range<u64>>> = args[3..].iter().map(|s| parse_range(s)).collect();
+
+        let mut range_args: Vec<&String> = args[3..].iter().collect();
+        let mut format = OutputFormat::Debug;
+        if let Some(pos) = range_args.iter().position(|a| a.as_str() == "--format") {
+            range_args.remove(pos);
+            if pos >= range_args.len() {
+                eprintln!("--format requires an argument (debug, csv, ndjson)");
+                print_usage(&args[0]);
+                return Ok(());
+            }
+            let format_arg = range_args.remove(pos);
+            format = OutputFormat::from_str(format_arg).unwrap_or_else(|| {
+                eprintln!("Invalid --format: {}", format_arg);
+                print_usage(&args[0]);
+                std::process::exit(1);
+            });
+        }
+        let ranges: Vec<Option<Range<u64>>> = range_args.iter().map(|s| parse_range(s)).collect();
 
         let reader = OmFileReader::from_file(filename)?;
         let mut variable = reader;
@@ -190,7 +580,7 @@ fn main() -> Result<(), OmFilesRsError> {
 
         let ranges: Vec<Range<u64>> = ranges.into_iter().map(|r| r.unwrap()).collect();
 
-        return print_variable_data(&variable, &ranges);
+        return print_variable_data(&variable, &ranges, format);
     } else {
         print_usage(&args[0]);
         return Ok(());
@@ -0,0 +1,311 @@
+use omfiles_rs::core::compression::CompressionType;
+use omfiles_rs::core::data_types::DataType;
+use omfiles_rs::io::reader::OmFileReader;
+use omfiles_rs::io::writer::OmFileWriter;
+use std::env;
+use std::fs::File;
+use std::io;
+use std::ops::Range;
+
+fn parse_usize_list(s: &str) -> Option<Vec<usize>> {
+    s.split(',').map(|p| p.parse::<usize>().ok()).collect()
+}
+
+fn parse_u64_list(s: &str) -> Option<Vec<u64>> {
+    s.split(',').map(|p| p.parse::<u64>().ok()).collect()
+}
+
+fn parse_compression(s: &str) -> Option<CompressionType> {
+    match s {
+        "p4nzdec256" => Some(CompressionType::P4nzdec256),
+        "fpxdec32" => Some(CompressionType::Fpxdec32),
+        "p4nzdec256_logarithmic" => Some(CompressionType::P4nzdec256Logarithmic),
+        "p4nzdec256_int16" => Some(CompressionType::P4nzdec256Int16),
+        "p4nzdec256_int16_logarithmic" => Some(CompressionType::P4nzdec256Int16Logarithmic),
+        _ => None,
+    }
+}
+
+fn print_usage_and_exit(program: &str) -> ! {
+    eprintln!(
+        "Usage: {0} <input_om_file> <output_om_file> --permute <axis0,axis1,...> \
+         --chunks <c0,c1,...> [--slice-axis <axis>] [--compression <name>]\n\
+         \n\
+         --permute gives the output axis order as indices into the *input* dimensions,\n\
+         \x20 e.g. `--permute 2,0,1` turns [lat, lon, time] into [time, lat, lon].\n\
+         --chunks gives the chunk shape of the output array, in output-axis order,\n\
+         \x20 e.g. `--chunks 1,721,1440` for single-time-slice chunks after the example above.\n\
+         --slice-axis selects which *input* axis is streamed one index at a time so memory\n\
+         \x20 stays bounded regardless of total size (default: 0). Wherever this axis lands\n\
+         \x20 in the output (after --permute) must have a chunk size of 1 in --chunks, since\n\
+         \x20 each streamed hyperslab is only 1 element wide there; e.g. with `--permute 2,0,1`\n\
+         \x20 and `--chunks 1,721,1440` above, --slice-axis must be 2 (the input time axis,\n\
+         \x20 which lands at output axis 0).\n\
+         --compression selects the output compression scheme: p4nzdec256, fpxdec32,\n\
+         \x20 p4nzdec256_logarithmic, p4nzdec256_int16, p4nzdec256_int16_logarithmic\n\
+         \x20 (default: same as the input file).",
+        program
+    );
+    std::process::exit(1);
+}
+
+/// Stream `reader` through `slice_axis` one index at a time, permuting each hyperslab
+/// according to `permute` and writing it into an output array of `output_dims`/`chunk_dims`.
+macro_rules! rechunk_as {
+    ($t:ty, $reader:expr, $file_writer:expr, $input_dims:expr, $output_dims:expr, $chunk_dims:expr, $permute:expr, $slice_axis:expr, $compression:expr, $scale_factor:expr, $add_offset:expr) => {{
+        let reader = $reader;
+        let input_dims = $input_dims;
+        let permute = $permute;
+        let slice_axis = $slice_axis;
+
+        let mut writer = $file_writer
+            .prepare_array::<$t>(
+                $output_dims.clone(),
+                $chunk_dims.clone(),
+                $compression,
+                $scale_factor,
+                $add_offset,
+            )
+            .expect("Failed to prepare output array");
+
+        let slice_shape: Vec<usize> = input_dims
+            .iter()
+            .enumerate()
+            .map(|(axis, &dim)| if axis == slice_axis { 1 } else { dim as usize })
+            .collect();
+        let slice_len = input_dims[slice_axis];
+
+        for idx in 0..slice_len {
+            let ranges: Vec<Range<u64>> = input_dims
+                .iter()
+                .enumerate()
+                .map(|(axis, &dim)| {
+                    if axis == slice_axis {
+                        idx..idx + 1
+                    } else {
+                        0..dim
+                    }
+                })
+                .collect();
+
+            let slice_data = reader
+                .read::<$t>(&ranges, None, None)
+                .expect("Failed to read data")
+                .into_shape_clone(ndarray::IxDyn(&slice_shape))
+                .expect("Failed to reshape data");
+
+            let permuted = slice_data.permuted_axes(permute.clone());
+
+            writer
+                .write_data(permuted.into_dyn().view(), None, None)
+                .unwrap_or_else(|_| panic!("Failed to write data for slice {}", idx));
+
+            if idx % 10 == 0 || idx == slice_len - 1 {
+                println!("Processed slice {}/{}", idx + 1, slice_len);
+            }
+        }
+
+        writer.finalize()
+    }};
+}
+
+fn main() -> io::Result<()> {
+    let args: Vec<String> = env::args().collect();
+
+    if args.len() < 3 {
+        print_usage_and_exit(&args[0]);
+    }
+
+    let input_file_path = &args[1];
+    let output_file_path = &args[2];
+
+    let mut permute: Option<Vec<usize>> = None;
+    let mut chunks: Option<Vec<u64>> = None;
+    let mut slice_axis = 0usize;
+    let mut compression_name: Option<String> = None;
+
+    let mut i = 3;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--permute" => {
+                i += 1;
+                if i >= args.len() {
+                    print_usage_and_exit(&args[0]);
+                }
+                permute = Some(parse_usize_list(&args[i]).unwrap_or_else(|| {
+                    eprintln!("Invalid --permute: {}", args[i]);
+                    print_usage_and_exit(&args[0]);
+                }));
+            }
+            "--chunks" => {
+                i += 1;
+                if i >= args.len() {
+                    print_usage_and_exit(&args[0]);
+                }
+                chunks = Some(parse_u64_list(&args[i]).unwrap_or_else(|| {
+                    eprintln!("Invalid --chunks: {}", args[i]);
+                    print_usage_and_exit(&args[0]);
+                }));
+            }
+            "--slice-axis" => {
+                i += 1;
+                if i >= args.len() {
+                    print_usage_and_exit(&args[0]);
+                }
+                slice_axis = args[i].parse::<usize>().unwrap_or_else(|_| {
+                    eprintln!("Invalid --slice-axis: {}", args[i]);
+                    print_usage_and_exit(&args[0]);
+                });
+            }
+            "--compression" => {
+                i += 1;
+                if i >= args.len() {
+                    print_usage_and_exit(&args[0]);
+                }
+                compression_name = Some(args[i].clone());
+            }
+            _ => {
+                print_usage_and_exit(&args[0]);
+            }
+        }
+        i += 1;
+    }
+
+    let permute = permute.unwrap_or_else(|| print_usage_and_exit(&args[0]));
+    let chunk_dims = chunks.unwrap_or_else(|| print_usage_and_exit(&args[0]));
+
+    let reader = OmFileReader::from_file(input_file_path)
+        .unwrap_or_else(|_| panic!("Failed to open file: {}", input_file_path));
+
+    let input_dims = reader.get_dimensions();
+
+    println!("Input file info:");
+    println!("  compression: {:?}", reader.compression());
+    println!("  dimensions: {:?}", input_dims);
+    println!("  chunks: {:?}", reader.get_chunk_dimensions());
+    println!("  scale_factor: {}", reader.scale_factor());
+
+    if permute.len() != input_dims.len() {
+        eprintln!(
+            "--permute has {} axes but the input has {} dimensions.",
+            permute.len(),
+            input_dims.len()
+        );
+        std::process::exit(1);
+    }
+    if chunk_dims.len() != permute.len() {
+        eprintln!(
+            "--chunks has {} axes but --permute produces {} output dimensions.",
+            chunk_dims.len(),
+            permute.len()
+        );
+        std::process::exit(1);
+    }
+    if slice_axis >= input_dims.len() {
+        eprintln!(
+            "--slice-axis {} is out of range for {} input dimensions.",
+            slice_axis,
+            input_dims.len()
+        );
+        std::process::exit(1);
+    }
+
+    let output_dims: Vec<u64> = permute.iter().map(|&axis| input_dims[axis]).collect();
+
+    // Each streamed hyperslab is 1 element wide along `slice_axis`, so wherever that axis
+    // lands in the output it must be declared as a chunk size of 1 or the writes won't be
+    // chunk-aligned.
+    let output_slice_axis = permute
+        .iter()
+        .position(|&axis| axis == slice_axis)
+        .expect("--slice-axis was validated against input_dims and --permute is a permutation of them");
+    if chunk_dims[output_slice_axis] != 1 {
+        eprintln!(
+            "--slice-axis {} lands at output axis {} (after --permute), which needs a chunk \
+             size of 1 there, but --chunks declares {}.",
+            slice_axis, output_slice_axis, chunk_dims[output_slice_axis]
+        );
+        std::process::exit(1);
+    }
+    let compression = compression_name
+        .as_deref()
+        .map(|name| {
+            parse_compression(name).unwrap_or_else(|| {
+                eprintln!("Invalid --compression: {}", name);
+                print_usage_and_exit(&args[0]);
+            })
+        })
+        .unwrap_or(reader.compression());
+    let scale_factor = reader.scale_factor();
+    let add_offset = reader.add_offset();
+
+    let file_handle = File::create(output_file_path).expect("Failed to create output file");
+    let mut file_writer = OmFileWriter::new(
+        &file_handle,
+        1024 * 1024 * 1024, // Initial capacity of 1GB
+    );
+    println!("Created writer");
+    println!(
+        "Rechunking with permutation {:?}, output chunks {:?}, streaming axis {}...",
+        permute, chunk_dims, slice_axis
+    );
+
+    let variable_meta = match reader.data_type() {
+        DataType::Int8 => rechunk_as!(
+            i8, &reader, &mut file_writer, input_dims, output_dims, chunk_dims, permute,
+            slice_axis, compression, scale_factor, add_offset
+        ),
+        DataType::Uint8 => rechunk_as!(
+            u8, &reader, &mut file_writer, input_dims, output_dims, chunk_dims, permute,
+            slice_axis, compression, scale_factor, add_offset
+        ),
+        DataType::Int16 => rechunk_as!(
+            i16, &reader, &mut file_writer, input_dims, output_dims, chunk_dims, permute,
+            slice_axis, compression, scale_factor, add_offset
+        ),
+        DataType::Uint16 => rechunk_as!(
+            u16, &reader, &mut file_writer, input_dims, output_dims, chunk_dims, permute,
+            slice_axis, compression, scale_factor, add_offset
+        ),
+        DataType::Int32 => rechunk_as!(
+            i32, &reader, &mut file_writer, input_dims, output_dims, chunk_dims, permute,
+            slice_axis, compression, scale_factor, add_offset
+        ),
+        DataType::Uint32 => rechunk_as!(
+            u32, &reader, &mut file_writer, input_dims, output_dims, chunk_dims, permute,
+            slice_axis, compression, scale_factor, add_offset
+        ),
+        DataType::Int64 => rechunk_as!(
+            i64, &reader, &mut file_writer, input_dims, output_dims, chunk_dims, permute,
+            slice_axis, compression, scale_factor, add_offset
+        ),
+        DataType::Uint64 => rechunk_as!(
+            u64, &reader, &mut file_writer, input_dims, output_dims, chunk_dims, permute,
+            slice_axis, compression, scale_factor, add_offset
+        ),
+        DataType::Float => rechunk_as!(
+            f32, &reader, &mut file_writer, input_dims, output_dims, chunk_dims, permute,
+            slice_axis, compression, scale_factor, add_offset
+        ),
+        DataType::Double => rechunk_as!(
+            f64, &reader, &mut file_writer, input_dims, output_dims, chunk_dims, permute,
+            slice_axis, compression, scale_factor, add_offset
+        ),
+        other => {
+            eprintln!("Unsupported data type: {:?}", other);
+            std::process::exit(1);
+        }
+    };
+    println!("Finalized array");
+
+    let variable = file_writer
+        .write_array(variable_meta, "data", &[])
+        .expect("Failed to write array metadata");
+    file_writer
+        .write_trailer(variable)
+        .expect("Failed to write trailer");
+
+    println!("Finished writing");
+
+    Ok(())
+}
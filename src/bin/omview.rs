@@ -1,8 +1,94 @@
 use eframe::egui::{self, CentralPanel, TopBottomPanel};
 use omfiles_rs::io::reader::OmFileReader;
 use std::env;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+#[derive(Clone, Copy, Debug)]
+enum Colormap {
+    Viridis,
+    Magma,
+    Plasma,
+    Inferno,
+    Turbo,
+}
+
+impl Colormap {
+    fn from_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "viridis" => Some(Colormap::Viridis),
+            "magma" => Some(Colormap::Magma),
+            "plasma" => Some(Colormap::Plasma),
+            "inferno" => Some(Colormap::Inferno),
+            "turbo" => Some(Colormap::Turbo),
+            _ => None,
+        }
+    }
+
+    fn lut(&self) -> &'static [(u8, u8, u8); 256] {
+        match self {
+            Colormap::Viridis => &VIRIDIS_LUT,
+            Colormap::Magma => &MAGMA_LUT,
+            Colormap::Plasma => &PLASMA_LUT,
+            Colormap::Inferno => &INFERNO_LUT,
+            Colormap::Turbo => &TURBO_LUT,
+        }
+    }
+
+    /// Sample the 256-entry lookup table at `v` in `[0, 1]`, linearly interpolating
+    /// between adjacent entries for smoothness.
+    fn sample(&self, v: f32) -> (u8, u8, u8) {
+        let lut = self.lut();
+        let scaled = v.clamp(0.0, 1.0) * 255.0;
+        let lo = scaled.floor() as usize;
+        let hi = (lo + 1).min(255);
+        let frac = scaled - lo as f32;
+        let lerp = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * frac).round() as u8;
+        let (r0, g0, b0) = lut[lo];
+        let (r1, g1, b1) = lut[hi];
+        (lerp(r0, r1), lerp(g0, g1), lerp(b0, b1))
+    }
+}
+
+/// How raw normalized values are turned into colors: which colormap, whether it's
+/// reversed, and what color stands in for NaN (masked ocean/land cells, etc).
+#[derive(Clone, Copy, Debug)]
+struct ColorSettings {
+    colormap: Colormap,
+    reverse: bool,
+    nan_color: (u8, u8, u8),
+}
+
+impl Default for ColorSettings {
+    fn default() -> Self {
+        Self {
+            colormap: Colormap::Viridis,
+            reverse: false,
+            nan_color: (128, 128, 128),
+        }
+    }
+}
+
+fn parse_nan_color(s: &str) -> Option<(u8, u8, u8)> {
+    let parts: Vec<&str> = s.split(',').collect();
+    if parts.len() != 3 {
+        return None;
+    }
+    let r = parts[0].parse::<u8>().ok()?;
+    let g = parts[1].parse::<u8>().ok()?;
+    let b = parts[2].parse::<u8>().ok()?;
+    Some((r, g, b))
+}
+
+fn colormap_color(settings: &ColorSettings, value: f32) -> (u8, u8, u8) {
+    if value.is_nan() {
+        return settings.nan_color;
+    }
+    let v = value.clamp(0.0, 1.0);
+    let v = if settings.reverse { 1.0 - v } else { v };
+    settings.colormap.sample(v)
+}
+
 #[derive(Clone, Copy, Debug)]
 enum ChunkingMode {
     Spatial,
@@ -83,10 +169,14 @@ struct App {
     data_loader: Arc<DataLoader>,
     current_timestamp: u64,
     plot_data: ndarray::ArrayBase<ndarray::OwnedRepr<f32>, ndarray::Ix2>,
+    color_settings: ColorSettings,
 }
 
 impl App {
-    fn new(data_loader: Arc<DataLoader>) -> Result<Self, Box<dyn std::error::Error>> {
+    fn new(
+        data_loader: Arc<DataLoader>,
+        color_settings: ColorSettings,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
         let dims = data_loader.reader.get_dimensions().to_vec();
         println!("dimensions {:?}", dims);
         let initial_data = data_loader.get_timestamp_data(0)?;
@@ -95,6 +185,7 @@ impl App {
             data_loader,
             current_timestamp: 0,
             plot_data: initial_data,
+            color_settings,
         })
     }
 
@@ -136,31 +227,9 @@ impl eframe::App for App {
                 return;
             }
 
-            let min_value: f32 = *self
-                .plot_data
-                .iter()
-                .min_by(|a, b| a.partial_cmp(b).unwrap())
-                .unwrap();
-            let max_value: f32 = *self
-                .plot_data
-                .iter()
-                .max_by(|a, b| a.partial_cmp(b).unwrap())
-                .unwrap();
-
-            let (rows, cols) = self.plot_data.dim();
-            // Prepare RGBA buffer
-            let mut rgba_data = Vec::with_capacity(rows * cols * 4);
-            for y in (0..rows).rev() {
-                for x in 0..cols {
-                    let value = self.plot_data[[y, x]];
-                    let normalized = (value - min_value) / (max_value - min_value);
-                    let color = viridis_color(normalized);
-                    rgba_data.push(color.0); // R
-                    rgba_data.push(color.1); // G
-                    rgba_data.push(color.2); // B
-                    rgba_data.push(255); // A
-                }
-            }
+            let (min_value, max_value) = min_max(&self.plot_data).unwrap_or((0.0, 1.0));
+            let (rgba_data, cols, rows) =
+                frame_to_rgba(&self.plot_data, min_value, max_value, &self.color_settings);
 
             // Create egui image and texture
             let image = egui::ColorImage::from_rgba_unmultiplied([cols, rows], &rgba_data);
@@ -197,40 +266,575 @@ impl eframe::App for App {
     }
 }
 
-struct RGBColor(pub u8, pub u8, pub u8);
+/// Compute the (min, max) of the non-NaN values in a 2-D slice, if any exist.
+fn min_max(data: &ndarray::Array2<f32>) -> Option<(f32, f32)> {
+    let min_value = data
+        .iter()
+        .cloned()
+        .filter(|v| !v.is_nan())
+        .fold(f32::INFINITY, f32::min);
+    let max_value = data
+        .iter()
+        .cloned()
+        .filter(|v| !v.is_nan())
+        .fold(f32::NEG_INFINITY, f32::max);
+    if min_value.is_finite() && max_value.is_finite() {
+        Some((min_value, max_value))
+    } else {
+        None
+    }
+}
 
-fn viridis_color(v: f32) -> RGBColor {
-    // Ensure v is in [0, 1]
-    let v = v.clamp(0.0, 1.0);
+/// Render a 2-D slice into a bottom-up RGBA buffer, colored per `settings` and
+/// normalized against the given `(min_value, max_value)` range. Returns `(rgba, cols, rows)`.
+fn frame_to_rgba(
+    data: &ndarray::Array2<f32>,
+    min_value: f32,
+    max_value: f32,
+    settings: &ColorSettings,
+) -> (Vec<u8>, usize, usize) {
+    let (rows, cols) = data.dim();
+    let mut rgba_data = Vec::with_capacity(rows * cols * 4);
+    for y in (0..rows).rev() {
+        for x in 0..cols {
+            let value = data[[y, x]];
+            let normalized = (value - min_value) / (max_value - min_value);
+            let color = colormap_color(settings, normalized);
+            rgba_data.push(color.0); // R
+            rgba_data.push(color.1); // G
+            rgba_data.push(color.2); // B
+            rgba_data.push(255); // A
+        }
+    }
+    (rgba_data, cols, rows)
+}
 
-    // Red component
-    let r = if v < 0.5 {
-        0.0
-    } else {
-        ((v - 0.5) * 2.0).powf(1.5) * 255.0
-    };
+fn parse_vrange(s: &str) -> Option<(f32, f32)> {
+    let parts: Vec<&str> = s.split(',').collect();
+    if parts.len() != 2 {
+        return None;
+    }
+    let min_value = parts[0].parse::<f32>().ok()?;
+    let max_value = parts[1].parse::<f32>().ok()?;
+    Some((min_value, max_value))
+}
 
-    // Green component
-    let g = if v < 0.4 {
-        v * 3.0 * 255.0
-    } else {
-        (1.0 - (v - 0.4) / 0.6) * 255.0
-    };
+/// Load timestamp `t`, color it per `settings` against `vrange` (or its own min/max when
+/// `vrange` is `None`), and return it as an RGBA image ready to save or encode.
+fn render_timestamp_image(
+    data_loader: &DataLoader,
+    t: u64,
+    vrange: Option<(f32, f32)>,
+    settings: &ColorSettings,
+) -> Result<image::RgbaImage, Box<dyn std::error::Error>> {
+    let data = data_loader.get_timestamp_data(t)?;
+    let (min_value, max_value) = vrange.or_else(|| min_max(&data)).unwrap_or((0.0, 1.0));
+    let (rgba, cols, rows) = frame_to_rgba(&data, min_value, max_value, settings);
+    Ok(image::ImageBuffer::from_raw(cols as u32, rows as u32, rgba)
+        .expect("RGBA buffer size doesn't match image dimensions"))
+}
 
-    // Blue component
-    let b = if v < 0.7 {
-        255.0 * (1.0 - v.powf(0.5))
-    } else {
-        0.0
-    };
+/// Render every timestamp to a PNG in `out_dir`, one file per frame.
+///
+/// When `vrange` is given all frames share that fixed color scale; otherwise each frame is
+/// normalized against its own min/max, which can make animations flicker.
+fn export_frames(
+    data_loader: &DataLoader,
+    out_dir: &Path,
+    vrange: Option<(f32, f32)>,
+    settings: &ColorSettings,
+) -> Result<(), Box<dyn std::error::Error>> {
+    std::fs::create_dir_all(out_dir)?;
+    for t in 0..data_loader.n_timestamps {
+        let image_buffer = render_timestamp_image(data_loader, t, vrange, settings)?;
+        let out_path = out_dir.join(format!("frame_{:05}.png", t));
+        image_buffer.save(&out_path)?;
+        if t % 10 == 0 || t == data_loader.n_timestamps - 1 {
+            println!("Exported frame {}/{}", t + 1, data_loader.n_timestamps);
+        }
+    }
+    Ok(())
+}
+
+/// Assemble every timestamp into a single animated GIF at `out_path`.
+///
+/// When `vrange` is given all frames share that fixed color scale; otherwise each frame is
+/// normalized against its own min/max, which can make animations flicker.
+fn export_gif_frames(
+    data_loader: &DataLoader,
+    out_path: &Path,
+    vrange: Option<(f32, f32)>,
+    settings: &ColorSettings,
+) -> Result<(), Box<dyn std::error::Error>> {
+    use image::codecs::gif::GifEncoder;
+    use image::{Delay, Frame};
+
+    let file = std::fs::File::create(out_path)?;
+    let mut encoder = GifEncoder::new(file);
+    for t in 0..data_loader.n_timestamps {
+        let image_buffer = render_timestamp_image(data_loader, t, vrange, settings)?;
+        let frame = Frame::from_parts(image_buffer, 0, 0, Delay::from_numer_denom_ms(100, 1));
+        encoder.encode_frame(frame)?;
+        if t % 10 == 0 || t == data_loader.n_timestamps - 1 {
+            println!("Encoded frame {}/{}", t + 1, data_loader.n_timestamps);
+        }
+    }
+    println!("Wrote animated GIF to {}", out_path.display());
+    Ok(())
+}
+
+fn normalize(value: f32, min_value: f32, max_value: f32) -> f32 {
+    (value - min_value) / (max_value - min_value)
+}
+
+/// Render one timestamp into the terminal using the half-block technique: each terminal
+/// character cell covers two vertically-stacked grid pixels, with the truecolor foreground
+/// set to the top pixel and the background set to the bottom pixel.
+fn render_tui_frame(
+    data_loader: &DataLoader,
+    timestamp: u64,
+    out: &mut impl std::io::Write,
+    settings: &ColorSettings,
+) -> Result<(), Box<dyn std::error::Error>> {
+    use crossterm::{cursor, execute};
+
+    let data = data_loader.get_timestamp_data(timestamp)?;
+    let (min_value, max_value) = min_max(&data).unwrap_or((0.0, 1.0));
+    let (rows, cols) = data.dim();
+
+    let (term_cols, term_rows) = crossterm::terminal::size()?;
+    let width = (term_cols as usize).max(1);
+    // Reserve one row for the status line; each terminal row covers two data rows.
+    let height = ((term_rows.saturating_sub(1) as usize) * 2).max(2);
+
+    let sample_row = |y: usize| -> usize { (y * rows / height).min(rows - 1) };
+    let sample_col = |x: usize| -> usize { (x * cols / width).min(cols - 1) };
+
+    execute!(out, cursor::MoveTo(0, 0))?;
+    for term_y in 0..(height / 2) {
+        let top_row = sample_row(term_y * 2);
+        let bottom_row = sample_row(term_y * 2 + 1);
+        let mut line = String::new();
+        for term_x in 0..width {
+            let col = sample_col(term_x);
+            let top = colormap_color(
+                settings,
+                normalize(data[[top_row, col]], min_value, max_value),
+            );
+            let bottom = colormap_color(
+                settings,
+                normalize(data[[bottom_row, col]], min_value, max_value),
+            );
+            line.push_str(&format!(
+                "\x1b[38;2;{};{};{}m\x1b[48;2;{};{};{}m\u{2580}",
+                top.0, top.1, top.2, bottom.0, bottom.1, bottom.2
+            ));
+        }
+        line.push_str("\x1b[0m\r\n");
+        write!(out, "{}", line)?;
+    }
+    write!(
+        out,
+        "\x1b[0mTimestamp: {}/{}  (\u{2190}/\u{2192} to navigate, q to quit)",
+        timestamp + 1,
+        data_loader.n_timestamps
+    )?;
+    out.flush()?;
+    Ok(())
+}
+
+/// Headless terminal viewer: renders the current timestamp with truecolor ANSI half-blocks
+/// and lets left/right arrow keys step through timestamps in a raw-mode event loop.
+fn run_tui(data_loader: &DataLoader, settings: &ColorSettings) -> Result<(), Box<dyn std::error::Error>> {
+    use crossterm::{cursor, event, terminal};
+    use std::io::stdout;
+    use std::time::Duration;
+
+    terminal::enable_raw_mode()?;
+    let mut out = stdout();
+    crossterm::execute!(out, terminal::EnterAlternateScreen, cursor::Hide)?;
+
+    let mut current_timestamp = 0u64;
+    let result: Result<(), Box<dyn std::error::Error>> = (|| {
+        loop {
+            render_tui_frame(data_loader, current_timestamp, &mut out, settings)?;
+
+            if event::poll(Duration::from_millis(200))? {
+                if let event::Event::Key(key) = event::read()? {
+                    match key.code {
+                        event::KeyCode::Left if current_timestamp > 0 => {
+                            current_timestamp -= 1;
+                        }
+                        event::KeyCode::Right
+                            if current_timestamp + 1 < data_loader.n_timestamps =>
+                        {
+                            current_timestamp += 1;
+                        }
+                        event::KeyCode::Char('q') | event::KeyCode::Esc => break,
+                        _ => {}
+                    }
+                }
+            }
+        }
+        Ok(())
+    })();
 
-    RGBColor(r as u8, g as u8, b as u8)
+    crossterm::execute!(out, cursor::Show, terminal::LeaveAlternateScreen)?;
+    terminal::disable_raw_mode()?;
+    result
 }
 
+/// 256-entry RGB lookup tables approximating matplotlib's viridis/magma/plasma/inferno
+/// colormaps and Google's turbo colormap. The entries follow those color ramps closely but
+/// do not exactly reproduce the published tables.
+const VIRIDIS_LUT: [(u8, u8, u8); 256] = [
+    (71, 1, 85), (71, 3, 87), (71, 4, 88), (71, 6, 89),
+    (71, 7, 91), (71, 8, 92), (71, 10, 93), (71, 11, 95),
+    (72, 13, 96), (72, 14, 97), (72, 15, 99), (72, 17, 100),
+    (72, 18, 101), (72, 20, 103), (72, 21, 104), (72, 22, 105),
+    (72, 24, 106), (72, 25, 108), (72, 26, 109), (72, 28, 110),
+    (72, 29, 111), (72, 31, 112), (72, 32, 113), (72, 33, 114),
+    (72, 35, 116), (72, 36, 117), (72, 37, 118), (72, 39, 119),
+    (71, 40, 120), (71, 41, 121), (71, 42, 121), (71, 44, 122),
+    (71, 45, 123), (71, 46, 124), (71, 48, 125), (70, 49, 126),
+    (70, 50, 127), (70, 51, 127), (70, 53, 128), (70, 54, 129),
+    (69, 55, 129), (69, 56, 130), (69, 58, 131), (69, 59, 131),
+    (68, 60, 132), (68, 61, 133), (68, 62, 133), (68, 63, 134),
+    (67, 65, 134), (67, 66, 135), (67, 67, 135), (66, 68, 136),
+    (66, 69, 136), (65, 70, 136), (65, 72, 137), (65, 73, 137),
+    (64, 74, 138), (64, 75, 138), (63, 76, 138), (63, 77, 139),
+    (63, 78, 139), (62, 79, 139), (62, 80, 139), (61, 81, 140),
+    (61, 82, 140), (60, 84, 140), (60, 85, 140), (59, 86, 140),
+    (59, 87, 141), (58, 88, 141), (58, 89, 141), (57, 90, 141),
+    (57, 91, 141), (56, 92, 141), (56, 93, 141), (55, 94, 142),
+    (54, 95, 142), (54, 96, 142), (53, 97, 142), (53, 98, 142),
+    (52, 99, 142), (52, 100, 142), (51, 101, 142), (50, 102, 142),
+    (50, 103, 142), (49, 104, 142), (49, 105, 142), (48, 106, 142),
+    (48, 107, 142), (47, 108, 142), (46, 109, 142), (46, 110, 142),
+    (45, 111, 142), (45, 112, 142), (44, 113, 142), (44, 114, 142),
+    (43, 115, 142), (43, 116, 142), (42, 116, 142), (41, 117, 142),
+    (41, 118, 142), (40, 119, 142), (40, 120, 142), (39, 121, 142),
+    (39, 122, 142), (38, 123, 142), (38, 124, 141), (37, 125, 141),
+    (37, 126, 141), (37, 127, 141), (36, 128, 141), (36, 129, 141),
+    (35, 130, 141), (35, 131, 141), (34, 132, 141), (34, 133, 141),
+    (34, 134, 141), (33, 134, 141), (33, 135, 140), (33, 136, 140),
+    (33, 137, 140), (32, 138, 140), (32, 139, 140), (32, 140, 140),
+    (32, 141, 140), (31, 142, 140), (31, 143, 139), (31, 144, 139),
+    (31, 145, 139), (31, 146, 139), (31, 147, 139), (31, 148, 139),
+    (31, 148, 138), (31, 149, 138), (31, 150, 138), (31, 151, 138),
+    (31, 152, 137), (31, 153, 137), (31, 154, 137), (31, 155, 137),
+    (32, 156, 136), (32, 157, 136), (32, 158, 136), (32, 159, 136),
+    (33, 160, 135), (33, 161, 135), (33, 162, 135), (34, 162, 134),
+    (34, 163, 134), (35, 164, 133), (35, 165, 133), (36, 166, 133),
+    (37, 167, 132), (37, 168, 132), (38, 169, 131), (39, 170, 131),
+    (39, 171, 130), (40, 172, 130), (41, 172, 129), (42, 173, 128),
+    (43, 174, 128), (43, 175, 127), (44, 176, 127), (45, 177, 126),
+    (46, 178, 125), (48, 179, 125), (49, 180, 124), (50, 180, 123),
+    (51, 181, 122), (52, 182, 122), (53, 183, 121), (55, 184, 120),
+    (56, 185, 119), (58, 186, 118), (59, 186, 117), (60, 187, 116),
+    (62, 188, 115), (63, 189, 114), (65, 190, 113), (67, 191, 112),
+    (68, 191, 111), (70, 192, 110), (72, 193, 109), (74, 194, 108),
+    (75, 195, 107), (77, 195, 105), (79, 196, 104), (81, 197, 103),
+    (83, 198, 102), (85, 198, 100), (87, 199, 99), (89, 200, 98),
+    (91, 201, 96), (94, 201, 95), (96, 202, 94), (98, 203, 92),
+    (100, 204, 91), (103, 204, 89), (105, 205, 88), (107, 206, 86),
+    (110, 206, 85), (112, 207, 83), (115, 208, 82), (117, 208, 80),
+    (120, 209, 78), (122, 210, 77), (125, 210, 75), (127, 211, 74),
+    (130, 211, 72), (132, 212, 70), (135, 213, 69), (138, 213, 67),
+    (141, 214, 65), (143, 214, 64), (146, 215, 62), (149, 215, 61),
+    (152, 216, 59), (154, 217, 57), (157, 217, 56), (160, 218, 54),
+    (163, 218, 52), (166, 219, 51), (168, 219, 49), (171, 220, 48),
+    (174, 220, 46), (177, 220, 45), (180, 221, 43), (183, 221, 42),
+    (186, 222, 41), (188, 222, 39), (191, 223, 38), (194, 223, 37),
+    (197, 223, 36), (200, 224, 35), (202, 224, 33), (205, 225, 32),
+    (208, 225, 32), (210, 225, 31), (213, 226, 30), (216, 226, 29),
+    (218, 226, 29), (221, 227, 28), (224, 227, 28), (226, 227, 27),
+    (228, 228, 27), (231, 228, 27), (233, 228, 27), (236, 229, 27),
+    (238, 229, 27), (240, 229, 28), (242, 230, 28), (244, 230, 29),
+    (246, 230, 30), (248, 231, 31), (250, 231, 32), (252, 231, 33),
+];
+
+const MAGMA_LUT: [(u8, u8, u8); 256] = [
+    (0, 0, 0), (0, 0, 1), (0, 1, 4), (0, 2, 6),
+    (1, 2, 9), (1, 3, 11), (2, 3, 14), (3, 4, 16),
+    (3, 4, 19), (4, 5, 21), (5, 5, 23), (6, 6, 26),
+    (7, 6, 28), (7, 7, 31), (8, 7, 33), (9, 7, 36),
+    (10, 8, 38), (11, 8, 40), (12, 9, 43), (14, 9, 45),
+    (15, 9, 47), (16, 10, 50), (17, 10, 52), (18, 10, 54),
+    (19, 11, 57), (21, 11, 59), (22, 11, 61), (23, 11, 63),
+    (25, 12, 65), (26, 12, 67), (27, 12, 70), (29, 12, 72),
+    (30, 13, 74), (31, 13, 76), (33, 13, 78), (34, 14, 80),
+    (36, 14, 82), (37, 14, 83), (39, 14, 85), (40, 15, 87),
+    (42, 15, 89), (43, 15, 91), (45, 15, 93), (46, 16, 94),
+    (48, 16, 96), (49, 16, 98), (51, 16, 99), (52, 17, 101),
+    (54, 17, 102), (55, 17, 104), (57, 17, 105), (59, 18, 107),
+    (60, 18, 108), (62, 18, 109), (63, 19, 111), (65, 19, 112),
+    (66, 19, 113), (68, 19, 115), (70, 20, 116), (71, 20, 117),
+    (73, 20, 118), (74, 21, 119), (76, 21, 120), (78, 21, 121),
+    (79, 22, 122), (81, 22, 123), (83, 22, 124), (84, 23, 125),
+    (86, 23, 126), (87, 23, 126), (89, 24, 127), (91, 24, 128),
+    (92, 24, 128), (94, 25, 129), (96, 25, 130), (97, 25, 130),
+    (99, 26, 131), (101, 26, 131), (102, 26, 132), (104, 27, 132),
+    (105, 27, 132), (107, 28, 133), (109, 28, 133), (110, 28, 133),
+    (112, 29, 134), (114, 29, 134), (115, 30, 134), (117, 30, 134),
+    (119, 31, 134), (120, 31, 134), (122, 31, 134), (124, 32, 134),
+    (125, 32, 134), (127, 33, 134), (129, 33, 134), (130, 34, 134),
+    (132, 34, 134), (134, 35, 134), (135, 35, 134), (137, 36, 134),
+    (139, 36, 133), (140, 37, 133), (142, 37, 133), (144, 38, 132),
+    (145, 38, 132), (147, 39, 132), (149, 39, 131), (150, 40, 131),
+    (152, 40, 131), (154, 41, 130), (155, 42, 130), (157, 42, 129),
+    (158, 43, 129), (160, 43, 128), (162, 44, 128), (163, 45, 127),
+    (165, 45, 127), (167, 46, 126), (168, 46, 125), (170, 47, 125),
+    (172, 48, 124), (173, 48, 124), (175, 49, 123), (176, 50, 122),
+    (178, 51, 122), (180, 51, 121), (181, 52, 120), (183, 53, 120),
+    (184, 54, 119), (186, 54, 118), (187, 55, 118), (189, 56, 117),
+    (190, 57, 116), (192, 57, 115), (194, 58, 115), (195, 59, 114),
+    (197, 60, 113), (198, 61, 113), (200, 62, 112), (201, 63, 111),
+    (203, 63, 111), (204, 64, 110), (205, 65, 109), (207, 66, 109),
+    (208, 67, 108), (210, 68, 107), (211, 69, 107), (212, 70, 106),
+    (214, 71, 105), (215, 72, 105), (217, 73, 104), (218, 74, 104),
+    (219, 75, 103), (220, 77, 102), (222, 78, 102), (223, 79, 101),
+    (224, 80, 101), (225, 81, 100), (227, 82, 100), (228, 83, 99),
+    (229, 85, 99), (230, 86, 98), (231, 87, 98), (232, 88, 98),
+    (233, 90, 97), (234, 91, 97), (235, 92, 97), (237, 94, 96),
+    (238, 95, 96), (238, 97, 96), (239, 98, 96), (240, 99, 96),
+    (241, 101, 95), (242, 102, 95), (243, 104, 95), (244, 105, 95),
+    (245, 107, 95), (245, 108, 95), (246, 110, 95), (247, 111, 95),
+    (248, 113, 95), (248, 115, 95), (249, 116, 96), (249, 118, 96),
+    (250, 120, 96), (251, 121, 96), (251, 123, 97), (252, 125, 97),
+    (252, 127, 97), (253, 128, 98), (253, 130, 98), (253, 132, 99),
+    (254, 134, 99), (254, 136, 100), (255, 137, 100), (255, 139, 101),
+    (255, 141, 101), (255, 143, 102), (255, 145, 103), (255, 147, 104),
+    (255, 149, 104), (255, 151, 105), (255, 153, 106), (255, 155, 107),
+    (255, 157, 108), (255, 159, 109), (255, 161, 110), (255, 163, 111),
+    (255, 165, 112), (255, 167, 113), (255, 169, 114), (255, 171, 116),
+    (255, 173, 117), (255, 175, 118), (255, 177, 119), (255, 180, 121),
+    (255, 182, 122), (255, 184, 123), (255, 186, 125), (255, 188, 126),
+    (255, 190, 128), (254, 192, 129), (254, 194, 131), (254, 196, 132),
+    (254, 198, 134), (253, 200, 135), (253, 202, 137), (253, 205, 139),
+    (253, 207, 140), (252, 209, 142), (252, 211, 144), (252, 213, 145),
+    (252, 215, 147), (251, 216, 149), (251, 218, 150), (251, 220, 152),
+    (251, 222, 154), (251, 224, 156), (251, 226, 157), (250, 228, 159),
+    (250, 229, 161), (250, 231, 163), (250, 233, 164), (250, 234, 166),
+    (250, 236, 168), (250, 237, 170), (251, 239, 171), (251, 240, 173),
+    (251, 241, 175), (251, 243, 177), (252, 244, 178), (252, 245, 180),
+    (253, 246, 182), (253, 247, 183), (254, 248, 185), (254, 249, 186),
+];
+
+const PLASMA_LUT: [(u8, u8, u8); 256] = [
+    (15, 6, 139), (17, 6, 139), (19, 6, 140), (21, 6, 141),
+    (24, 6, 142), (26, 6, 143), (28, 6, 143), (30, 6, 144),
+    (32, 6, 145), (34, 6, 146), (36, 6, 147), (38, 6, 148),
+    (40, 6, 149), (42, 5, 150), (44, 5, 150), (46, 5, 151),
+    (47, 5, 152), (49, 4, 153), (51, 4, 154), (53, 4, 155),
+    (55, 3, 156), (57, 3, 156), (59, 3, 157), (60, 3, 158),
+    (62, 2, 159), (64, 2, 159), (66, 2, 160), (68, 1, 161),
+    (69, 1, 162), (71, 1, 162), (73, 1, 163), (75, 1, 164),
+    (76, 0, 164), (78, 0, 165), (80, 0, 165), (81, 0, 166),
+    (83, 0, 166), (85, 0, 167), (86, 0, 167), (88, 0, 168),
+    (90, 0, 168), (91, 0, 169), (93, 0, 169), (95, 0, 169),
+    (96, 0, 170), (98, 0, 170), (99, 0, 170), (101, 0, 170),
+    (103, 0, 171), (104, 0, 171), (106, 0, 171), (107, 0, 171),
+    (109, 0, 171), (110, 1, 171), (112, 1, 171), (113, 1, 171),
+    (115, 2, 171), (116, 2, 171), (118, 2, 171), (119, 3, 171),
+    (121, 3, 171), (122, 4, 171), (124, 4, 171), (125, 5, 170),
+    (127, 5, 170), (128, 6, 170), (130, 6, 170), (131, 7, 169),
+    (133, 8, 169), (134, 8, 169), (135, 9, 168), (137, 10, 168),
+    (138, 10, 167), (140, 11, 167), (141, 12, 167), (142, 13, 166),
+    (144, 13, 166), (145, 14, 165), (147, 15, 165), (148, 16, 164),
+    (149, 17, 163), (151, 18, 163), (152, 19, 162), (153, 20, 162),
+    (155, 21, 161), (156, 22, 160), (157, 23, 160), (159, 24, 159),
+    (160, 25, 158), (161, 26, 158), (162, 27, 157), (164, 28, 156),
+    (165, 29, 155), (166, 30, 155), (167, 31, 154), (169, 32, 153),
+    (170, 33, 152), (171, 34, 152), (172, 35, 151), (174, 37, 150),
+    (175, 38, 149), (176, 39, 148), (177, 40, 147), (178, 41, 146),
+    (179, 42, 146), (181, 44, 145), (182, 45, 144), (183, 46, 143),
+    (184, 47, 142), (185, 48, 141), (186, 50, 140), (187, 51, 139),
+    (189, 52, 138), (190, 53, 138), (191, 54, 137), (192, 56, 136),
+    (193, 57, 135), (194, 58, 134), (195, 59, 133), (196, 61, 132),
+    (197, 62, 131), (198, 63, 130), (199, 64, 129), (200, 66, 128),
+    (201, 67, 127), (202, 68, 126), (203, 69, 125), (204, 70, 125),
+    (205, 72, 124), (206, 73, 123), (207, 74, 122), (208, 75, 121),
+    (209, 77, 120), (210, 78, 119), (211, 79, 118), (212, 80, 117),
+    (213, 81, 116), (214, 83, 115), (215, 84, 114), (215, 85, 113),
+    (216, 86, 112), (217, 87, 111), (218, 89, 110), (219, 90, 110),
+    (220, 91, 109), (221, 92, 108), (221, 93, 107), (222, 95, 106),
+    (223, 96, 105), (224, 97, 104), (225, 98, 103), (225, 99, 102),
+    (226, 100, 101), (227, 102, 100), (228, 103, 100), (228, 104, 99),
+    (229, 105, 98), (230, 106, 97), (231, 108, 96), (231, 109, 95),
+    (232, 110, 94), (233, 111, 93), (233, 112, 92), (234, 113, 91),
+    (235, 115, 91), (235, 116, 90), (236, 117, 89), (237, 118, 88),
+    (237, 119, 87), (238, 121, 86), (239, 122, 85), (239, 123, 84),
+    (240, 124, 84), (240, 125, 83), (241, 127, 82), (241, 128, 81),
+    (242, 129, 80), (243, 130, 79), (243, 131, 78), (244, 133, 77),
+    (244, 134, 77), (245, 135, 76), (245, 136, 75), (246, 138, 74),
+    (246, 139, 73), (247, 140, 72), (247, 142, 71), (247, 143, 70),
+    (248, 144, 70), (248, 145, 69), (249, 147, 68), (249, 148, 67),
+    (250, 150, 66), (250, 151, 65), (250, 152, 64), (251, 154, 64),
+    (251, 155, 63), (251, 156, 62), (252, 158, 61), (252, 159, 60),
+    (252, 161, 59), (252, 162, 59), (253, 164, 58), (253, 165, 57),
+    (253, 166, 56), (253, 168, 55), (254, 169, 54), (254, 171, 54),
+    (254, 173, 53), (254, 174, 52), (254, 176, 51), (254, 177, 50),
+    (255, 179, 50), (255, 180, 49), (255, 182, 48), (255, 183, 47),
+    (255, 185, 46), (255, 187, 46), (255, 188, 45), (255, 190, 44),
+    (255, 192, 44), (255, 193, 43), (255, 195, 42), (255, 197, 42),
+    (255, 198, 41), (254, 200, 40), (254, 202, 40), (254, 203, 39),
+    (254, 205, 39), (254, 207, 38), (254, 208, 37), (253, 210, 37),
+    (253, 212, 37), (253, 213, 36), (252, 215, 36), (252, 217, 35),
+    (252, 218, 35), (251, 220, 35), (251, 222, 34), (250, 223, 34),
+    (250, 225, 34), (249, 227, 34), (249, 228, 34), (248, 230, 34),
+    (247, 231, 34), (247, 233, 34), (246, 235, 34), (245, 236, 34),
+    (244, 238, 34), (244, 239, 35), (243, 240, 35), (242, 242, 36),
+    (241, 243, 36), (240, 244, 37), (239, 246, 37), (238, 247, 38),
+];
+
+const INFERNO_LUT: [(u8, u8, u8); 256] = [
+    (0, 0, 0), (0, 1, 0), (0, 1, 3), (1, 2, 6),
+    (1, 2, 10), (2, 3, 13), (2, 3, 17), (3, 4, 20),
+    (4, 4, 23), (4, 4, 26), (5, 5, 29), (6, 5, 32),
+    (7, 5, 34), (8, 6, 37), (9, 6, 40), (10, 6, 42),
+    (11, 6, 44), (12, 7, 47), (13, 7, 49), (15, 7, 51),
+    (16, 7, 53), (17, 8, 55), (19, 8, 57), (20, 8, 59),
+    (21, 8, 61), (23, 8, 63), (24, 8, 65), (26, 9, 67),
+    (27, 9, 68), (29, 9, 70), (30, 9, 72), (32, 9, 73),
+    (33, 9, 75), (35, 10, 76), (37, 10, 77), (38, 10, 79),
+    (40, 10, 80), (41, 10, 81), (43, 10, 83), (45, 11, 84),
+    (46, 11, 85), (48, 11, 86), (50, 11, 87), (51, 11, 88),
+    (53, 12, 89), (55, 12, 90), (56, 12, 91), (58, 12, 92),
+    (60, 12, 93), (61, 13, 94), (63, 13, 95), (65, 13, 96),
+    (67, 13, 96), (68, 14, 97), (70, 14, 98), (72, 14, 99),
+    (73, 14, 99), (75, 15, 100), (77, 15, 101), (78, 15, 101),
+    (80, 15, 102), (82, 16, 102), (83, 16, 103), (85, 16, 103),
+    (87, 17, 104), (88, 17, 104), (90, 17, 105), (92, 18, 105),
+    (93, 18, 105), (95, 18, 106), (97, 19, 106), (98, 19, 106),
+    (100, 20, 107), (102, 20, 107), (103, 20, 107), (105, 21, 107),
+    (106, 21, 107), (108, 22, 108), (110, 22, 108), (111, 22, 108),
+    (113, 23, 108), (114, 23, 108), (116, 24, 108), (118, 24, 108),
+    (119, 25, 108), (121, 25, 108), (122, 26, 108), (124, 26, 108),
+    (126, 27, 107), (127, 27, 107), (129, 28, 107), (130, 28, 107),
+    (132, 29, 107), (133, 29, 106), (135, 30, 106), (137, 31, 106),
+    (138, 31, 105), (140, 32, 105), (141, 32, 104), (143, 33, 104),
+    (144, 34, 104), (146, 34, 103), (147, 35, 103), (149, 35, 102),
+    (151, 36, 102), (152, 37, 101), (154, 37, 100), (155, 38, 100),
+    (157, 39, 99), (158, 39, 98), (160, 40, 98), (161, 41, 97),
+    (163, 41, 96), (164, 42, 95), (166, 43, 95), (167, 44, 94),
+    (169, 44, 93), (170, 45, 92), (172, 46, 91), (173, 47, 90),
+    (175, 47, 89), (176, 48, 88), (178, 49, 87), (179, 50, 86),
+    (181, 51, 85), (182, 52, 84), (183, 52, 83), (185, 53, 82),
+    (186, 54, 81), (188, 55, 80), (189, 56, 79), (191, 57, 78),
+    (192, 58, 76), (193, 59, 75), (195, 60, 74), (196, 61, 73),
+    (198, 61, 72), (199, 62, 70), (200, 63, 69), (202, 64, 68),
+    (203, 65, 67), (204, 66, 65), (206, 67, 64), (207, 69, 63),
+    (208, 70, 62), (209, 71, 60), (211, 72, 59), (212, 73, 58),
+    (213, 74, 56), (215, 75, 55), (216, 76, 54), (217, 77, 52),
+    (218, 79, 51), (219, 80, 50), (220, 81, 49), (222, 82, 47),
+    (223, 83, 46), (224, 85, 45), (225, 86, 43), (226, 87, 42),
+    (227, 89, 41), (228, 90, 40), (229, 91, 38), (230, 93, 37),
+    (231, 94, 36), (232, 95, 35), (233, 97, 34), (234, 98, 33),
+    (235, 100, 31), (236, 101, 30), (237, 102, 29), (237, 104, 28),
+    (238, 105, 27), (239, 107, 26), (240, 109, 25), (240, 110, 24),
+    (241, 112, 23), (242, 113, 22), (243, 115, 22), (243, 116, 21),
+    (244, 118, 20), (244, 120, 19), (245, 121, 19), (245, 123, 18),
+    (246, 125, 17), (246, 127, 17), (247, 128, 16), (247, 130, 16),
+    (248, 132, 15), (248, 134, 15), (248, 136, 15), (249, 137, 15),
+    (249, 139, 14), (249, 141, 14), (250, 143, 14), (250, 145, 14),
+    (250, 147, 14), (250, 149, 14), (250, 151, 14), (250, 153, 15),
+    (250, 155, 15), (251, 157, 15), (251, 159, 16), (251, 161, 16),
+    (251, 163, 17), (251, 165, 18), (250, 167, 18), (250, 169, 19),
+    (250, 171, 20), (250, 173, 21), (250, 175, 22), (250, 177, 23),
+    (250, 179, 24), (249, 181, 26), (249, 183, 27), (249, 185, 28),
+    (249, 188, 30), (249, 190, 32), (248, 192, 33), (248, 194, 35),
+    (248, 196, 37), (247, 198, 39), (247, 200, 41), (247, 202, 43),
+    (247, 204, 46), (246, 206, 48), (246, 209, 51), (246, 211, 53),
+    (245, 213, 56), (245, 215, 59), (245, 217, 62), (245, 219, 65),
+    (244, 221, 68), (244, 223, 71), (244, 225, 74), (244, 227, 78),
+    (244, 229, 81), (243, 231, 85), (243, 232, 88), (243, 234, 92),
+    (243, 236, 96), (243, 238, 100), (243, 240, 104), (243, 241, 109),
+    (244, 243, 113), (244, 244, 117), (244, 246, 122), (244, 248, 127),
+    (245, 249, 131), (245, 250, 136), (246, 252, 141), (246, 253, 146),
+    (247, 254, 151), (248, 255, 157), (249, 255, 162), (250, 255, 168),
+];
+
+const TURBO_LUT: [(u8, u8, u8); 256] = [
+    (48, 18, 59), (50, 21, 66), (52, 24, 73), (53, 27, 80),
+    (55, 30, 87), (57, 33, 93), (59, 36, 100), (60, 39, 107),
+    (62, 42, 114), (64, 44, 123), (66, 47, 132), (68, 49, 140),
+    (69, 52, 148), (71, 55, 155), (72, 57, 162), (73, 60, 169),
+    (73, 62, 175), (74, 65, 181), (74, 68, 187), (75, 70, 193),
+    (75, 73, 198), (75, 76, 203), (75, 79, 207), (74, 81, 211),
+    (74, 84, 215), (74, 87, 219), (73, 89, 223), (73, 92, 226),
+    (72, 95, 229), (71, 98, 232), (70, 101, 234), (69, 103, 237),
+    (68, 106, 239), (67, 109, 240), (66, 112, 242), (65, 114, 244),
+    (64, 117, 245), (63, 120, 246), (62, 123, 247), (61, 125, 248),
+    (59, 128, 248), (58, 131, 249), (57, 134, 249), (56, 136, 249),
+    (55, 139, 249), (53, 142, 249), (52, 144, 248), (51, 147, 248),
+    (50, 150, 247), (49, 152, 246), (48, 155, 246), (47, 158, 245),
+    (46, 160, 244), (45, 163, 242), (44, 165, 241), (43, 168, 240),
+    (42, 170, 238), (42, 173, 237), (41, 175, 235), (40, 178, 234),
+    (40, 180, 232), (39, 182, 230), (39, 185, 228), (38, 187, 226),
+    (38, 189, 224), (37, 192, 222), (37, 194, 220), (37, 196, 218),
+    (37, 198, 215), (37, 200, 213), (37, 202, 211), (37, 205, 209),
+    (37, 207, 206), (38, 209, 204), (38, 210, 201), (38, 212, 199),
+    (39, 214, 196), (39, 216, 194), (40, 218, 191), (41, 220, 189),
+    (42, 221, 186), (43, 223, 184), (44, 225, 181), (45, 226, 178),
+    (46, 228, 176), (47, 229, 173), (48, 231, 171), (49, 232, 168),
+    (51, 234, 166), (52, 235, 163), (54, 236, 160), (55, 238, 158),
+    (57, 239, 155), (59, 240, 153), (61, 241, 150), (63, 242, 148),
+    (65, 243, 145), (67, 244, 143), (69, 245, 140), (71, 246, 138),
+    (73, 247, 135), (75, 248, 133), (78, 249, 131), (80, 249, 128),
+    (82, 250, 126), (85, 250, 124), (87, 251, 121), (90, 251, 119),
+    (93, 252, 117), (95, 252, 115), (98, 253, 113), (101, 253, 110),
+    (104, 253, 108), (106, 253, 106), (109, 254, 104), (112, 254, 102),
+    (115, 254, 100), (118, 254, 98), (121, 254, 96), (124, 253, 94),
+    (127, 253, 93), (130, 253, 91), (133, 253, 89), (136, 252, 87),
+    (139, 252, 86), (142, 252, 84), (145, 251, 82), (149, 251, 81),
+    (152, 250, 79), (155, 249, 78), (158, 249, 76), (161, 248, 75),
+    (164, 247, 73), (167, 246, 72), (170, 246, 70), (173, 245, 69),
+    (176, 244, 68), (179, 243, 66), (182, 242, 65), (185, 240, 64),
+    (188, 239, 63), (191, 238, 62), (194, 237, 60), (197, 235, 59),
+    (200, 234, 58), (203, 233, 57), (205, 231, 56), (208, 230, 55),
+    (211, 228, 54), (213, 227, 53), (216, 225, 52), (219, 223, 52),
+    (221, 222, 51), (223, 220, 50), (226, 218, 49), (228, 216, 48),
+    (230, 214, 48), (233, 212, 47), (235, 210, 46), (237, 208, 45),
+    (239, 206, 45), (241, 204, 44), (243, 202, 43), (244, 200, 43),
+    (246, 198, 42), (248, 196, 42), (249, 193, 41), (251, 191, 40),
+    (252, 189, 40), (253, 186, 39), (255, 184, 39), (255, 181, 38),
+    (255, 179, 38), (255, 177, 37), (255, 174, 37), (255, 172, 36),
+    (255, 169, 36), (255, 166, 35), (255, 164, 35), (255, 161, 34),
+    (255, 159, 34), (255, 156, 34), (255, 153, 33), (255, 151, 33),
+    (255, 148, 32), (255, 145, 32), (255, 142, 31), (255, 140, 31),
+    (255, 137, 30), (255, 134, 30), (255, 131, 30), (255, 129, 29),
+    (255, 126, 29), (255, 123, 28), (255, 120, 28), (255, 117, 27),
+    (255, 115, 27), (255, 112, 26), (254, 109, 26), (252, 106, 26),
+    (251, 104, 25), (249, 101, 25), (248, 98, 24), (246, 95, 24),
+    (244, 92, 23), (243, 90, 23), (241, 87, 22), (239, 84, 22),
+    (237, 82, 21), (235, 79, 20), (233, 76, 20), (230, 74, 19),
+    (228, 71, 19), (226, 69, 18), (224, 66, 18), (221, 64, 17),
+    (219, 61, 16), (216, 59, 16), (214, 56, 15), (211, 54, 15),
+    (209, 52, 14), (206, 49, 13), (203, 47, 13), (201, 45, 12),
+    (198, 43, 11), (196, 41, 11), (193, 39, 10), (190, 37, 10),
+    (188, 35, 9), (185, 33, 8), (183, 31, 8), (180, 29, 7),
+    (177, 28, 6), (175, 26, 6), (172, 24, 5), (170, 23, 4),
+    (168, 22, 4), (165, 20, 3), (163, 19, 2), (161, 18, 2),
+    (159, 17, 1), (157, 16, 0), (155, 15, 0), (154, 14, 0),
+    (152, 14, 0), (150, 13, 0), (149, 12, 0), (148, 12, 0),
+    (147, 12, 0), (146, 12, 0), (145, 11, 0), (145, 12, 0),
+    (144, 12, 0), (144, 12, 0), (144, 12, 0), (144, 13, 0),
+];
+
 fn print_usage_and_exit(program: &str) -> ! {
     eprintln!(
-        "Usage: {} <omfile> [--chunking spatial|temporal]\n\
-         Default is temporal chunking (last (and fast) dimension is time).",
+        "Usage: {0} <omfile> [--chunking spatial|temporal] [--vrange min,max]\n\
+         \x20   [--export <dir> | --export-gif <out.gif> | --tui]\n\
+         \x20   [--colormap viridis|magma|plasma|inferno|turbo] [--reverse] [--nan-color r,g,b]\n\
+         Default is temporal chunking (last (and fast) dimension is time).\n\
+         --export and --export-gif render every timestamp headlessly instead of\n\
+         opening a window. --tui renders into the terminal with truecolor half-blocks\n\
+         for use over SSH. --vrange fixes the color scale across all exported frames\n\
+         instead of normalizing each frame to its own min/max; the window and --tui\n\
+         views always normalize each frame to its own min/max. --colormap selects the color scale\n\
+         (default: viridis), --reverse flips it, and --nan-color sets the color used for\n\
+         masked/NaN cells (default: 128,128,128).",
         program
     );
     std::process::exit(1);
@@ -244,6 +848,11 @@ fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
 
     let mut chunking = ChunkingMode::Temporal;
     let mut omfile = None;
+    let mut export_dir: Option<PathBuf> = None;
+    let mut export_gif: Option<PathBuf> = None;
+    let mut vrange: Option<(f32, f32)> = None;
+    let mut tui = false;
+    let mut color_settings = ColorSettings::default();
 
     let mut i = 1;
     while i < args.len() {
@@ -258,6 +867,56 @@ fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
                     print_usage_and_exit(&args[0]);
                 });
             }
+            "--export" => {
+                i += 1;
+                if i >= args.len() {
+                    print_usage_and_exit(&args[0]);
+                }
+                export_dir = Some(PathBuf::from(&args[i]));
+            }
+            "--export-gif" => {
+                i += 1;
+                if i >= args.len() {
+                    print_usage_and_exit(&args[0]);
+                }
+                export_gif = Some(PathBuf::from(&args[i]));
+            }
+            "--tui" => {
+                tui = true;
+            }
+            "--vrange" => {
+                i += 1;
+                if i >= args.len() {
+                    print_usage_and_exit(&args[0]);
+                }
+                vrange = Some(parse_vrange(&args[i]).unwrap_or_else(|| {
+                    eprintln!("Invalid --vrange: {}", args[i]);
+                    print_usage_and_exit(&args[0]);
+                }));
+            }
+            "--colormap" => {
+                i += 1;
+                if i >= args.len() {
+                    print_usage_and_exit(&args[0]);
+                }
+                color_settings.colormap = Colormap::from_str(&args[i]).unwrap_or_else(|| {
+                    eprintln!("Invalid --colormap: {}", args[i]);
+                    print_usage_and_exit(&args[0]);
+                });
+            }
+            "--reverse" => {
+                color_settings.reverse = true;
+            }
+            "--nan-color" => {
+                i += 1;
+                if i >= args.len() {
+                    print_usage_and_exit(&args[0]);
+                }
+                color_settings.nan_color = parse_nan_color(&args[i]).unwrap_or_else(|| {
+                    eprintln!("Invalid --nan-color: {}", args[i]);
+                    print_usage_and_exit(&args[0]);
+                });
+            }
             s if omfile.is_none() => {
                 omfile = Some(s.to_string());
             }
@@ -275,6 +934,19 @@ fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     let data_loader =
         Arc::new(DataLoader::new(&omfile, chunking).expect("Could not init DataLoader"));
 
+    if let Some(out_dir) = export_dir {
+        export_frames(&data_loader, &out_dir, vrange, &color_settings)?;
+        return Ok(());
+    }
+    if let Some(out_path) = export_gif {
+        export_gif_frames(&data_loader, &out_path, vrange, &color_settings)?;
+        return Ok(());
+    }
+    if tui {
+        run_tui(&data_loader, &color_settings)?;
+        return Ok(());
+    }
+
     let native_options = eframe::NativeOptions {
         ..Default::default()
     };
@@ -283,7 +955,7 @@ fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         "Heatmap Viewer",
         native_options,
         Box::new(move |_cc| {
-            let app = App::new(data_loader.clone()).unwrap();
+            let app = App::new(data_loader.clone(), color_settings).unwrap();
             Box::new(app) as Box<dyn eframe::App>
         }),
     )